@@ -0,0 +1,193 @@
+//! Channel remixing over a frame iterator: replicate mono, reorder channels,
+//! or mix down to fewer channels with a weight matrix.
+
+use crate::sample::NumIO;
+
+/// The operation a [`ChannelConverter`] applies to each frame, selected
+/// automatically from the source/target channel counts unless one is supplied
+/// explicitly.
+#[derive(Clone, Debug)]
+pub enum ChannelOp {
+    /// Replicate a single-channel frame into `target` identical channels.
+    DupMono,
+    /// Permute channels: `target[i] = source[mapping[i]]`.
+    Reorder(Vec<usize>),
+    /// Combine channels with a `target × source` weight matrix, the arithmetic
+    /// done in `f64`.
+    Remix(Vec<Vec<f64>>),
+}
+
+/// Default `target × source` downmix weights for the common layouts. Unknown
+/// combinations fall back to a 1:1 map of the leading channels (extra source
+/// channels dropped, missing target channels left silent).
+fn default_weights(source: usize, target: usize) -> Vec<Vec<f64>> {
+    const SQRT1_2: f64 = std::f64::consts::FRAC_1_SQRT_2;
+    match (source, target) {
+        // Stereo -> mono: average.
+        (2, 1) => vec![vec![0.5, 0.5]],
+        // 5.1 (FL FR FC LFE BL BR) -> stereo, LFE discarded.
+        (6, 2) => vec![
+            vec![1.0, 0.0, SQRT1_2, 0.0, SQRT1_2, 0.0],
+            vec![0.0, 1.0, SQRT1_2, 0.0, 0.0, SQRT1_2],
+        ],
+        // Downmix to mono: even average of every source channel.
+        (_, 1) => vec![vec![1.0 / source as f64; source]],
+        _ => (0..target)
+            .map(|t| {
+                let mut row = vec![0.0; source];
+                if t < source {
+                    row[t] = 1.0;
+                }
+                row
+            })
+            .collect(),
+    }
+}
+
+/// Frame adapter that re-channels an `Iterator<Item = Vec<T>>` from `source`
+/// channels to `target` channels.
+pub struct ChannelConverter<I> {
+    inner: I,
+    source: usize,
+    target: usize,
+    op: ChannelOp,
+}
+
+impl<I> ChannelConverter<I> {
+    /// Build a converter, choosing the operation from the channel counts:
+    /// mono sources are duplicated, equal counts are passed through, and every
+    /// other case uses [`default_weights`].
+    pub fn new(inner: I, source: usize, target: usize) -> Self {
+        let op = if source == 1 && target > 1 {
+            ChannelOp::DupMono
+        } else if source == target {
+            ChannelOp::Reorder((0..target).collect())
+        } else {
+            ChannelOp::Remix(default_weights(source, target))
+        };
+        Self {
+            inner,
+            source,
+            target,
+            op,
+        }
+    }
+
+    /// Build a converter with an explicit channel permutation.
+    pub fn with_mapping(inner: I, source: usize, target: usize, mapping: Vec<usize>) -> Self {
+        assert_eq!(mapping.len(), target);
+        Self {
+            inner,
+            source,
+            target,
+            op: ChannelOp::Reorder(mapping),
+        }
+    }
+
+    /// Build a converter with an explicit `target × source` weight matrix.
+    pub fn with_weights(inner: I, source: usize, target: usize, weights: Vec<Vec<f64>>) -> Self {
+        assert_eq!(weights.len(), target);
+        assert!(weights.iter().all(|row| row.len() == source));
+        Self {
+            inner,
+            source,
+            target,
+            op: ChannelOp::Remix(weights),
+        }
+    }
+
+    /// Target channel count of the produced frames.
+    pub fn channels(&self) -> usize {
+        self.target
+    }
+}
+
+impl<T: NumIO + Copy, I: Iterator<Item = Vec<T>>> Iterator for ChannelConverter<I> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.inner.next()?;
+        debug_assert_eq!(frame.len(), self.source);
+        let out = match &self.op {
+            ChannelOp::DupMono => vec![frame[0]; self.target],
+            ChannelOp::Reorder(mapping) => mapping.iter().map(|&s| frame[s]).collect(),
+            ChannelOp::Remix(weights) => weights
+                .iter()
+                .map(|row| {
+                    let acc: f64 = row
+                        .iter()
+                        .zip(&frame)
+                        .map(|(w, s)| w * s.to_f64())
+                        .sum();
+                    // Integer sample types saturate in `from_f64`.
+                    T::from_f64(acc)
+                })
+                .collect(),
+        };
+        Some(out)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert<T: NumIO + Copy>(
+        frames: Vec<Vec<T>>,
+        source: usize,
+        target: usize,
+    ) -> Vec<Vec<T>> {
+        ChannelConverter::new(frames.into_iter(), source, target).collect()
+    }
+
+    #[test]
+    fn test_dup_mono() {
+        let out = convert(vec![vec![7i16], vec![-3]], 1, 3);
+        assert_eq!(out, vec![vec![7, 7, 7], vec![-3, -3, -3]]);
+    }
+
+    #[test]
+    fn test_remix_stereo_to_mono() {
+        let out = convert(vec![vec![100i16, 200], vec![-50, 50]], 2, 1);
+        assert_eq!(out, vec![vec![150], vec![0]]);
+    }
+
+    #[test]
+    fn test_remix_surround_to_stereo() {
+        // FL FR FC LFE BL BR, all unit so the weighted centre/back channels
+        // show up at their coefficients.
+        const SQRT1_2: f64 = std::f64::consts::FRAC_1_SQRT_2;
+        let frame = vec![1.0f64, 1.0, 1.0, 1.0, 1.0, 1.0];
+        let out = convert(vec![frame], 6, 2);
+        let expected = 1.0 + 2.0 * SQRT1_2;
+        assert_eq!(out, vec![vec![expected, expected]]);
+    }
+
+    #[test]
+    fn test_reorder_mapping() {
+        let conv = ChannelConverter::with_mapping(
+            vec![vec![1i16, 2, 3]].into_iter(),
+            3,
+            3,
+            vec![2, 0, 1],
+        );
+        assert_eq!(conv.collect::<Vec<_>>(), vec![vec![3, 1, 2]]);
+    }
+
+    #[test]
+    fn test_remix_saturates() {
+        // Summing two full-scale channels at unit weight overflows `i16` and
+        // must clamp rather than wrap.
+        let conv = ChannelConverter::with_weights(
+            vec![vec![i16::MAX, i16::MAX]].into_iter(),
+            2,
+            1,
+            vec![vec![1.0, 1.0]],
+        );
+        assert_eq!(conv.collect::<Vec<_>>(), vec![vec![i16::MAX]]);
+    }
+}
@@ -6,12 +6,15 @@ use std::marker::PhantomData;
 
 use crate::lowlevel::writer::{WavWriter, WriteError};
 use crate::lowlevel::AudioFormat;
-use crate::sample::NumIO;
+use crate::sample::{Endianness, NumIO};
 
 #[cfg(test)]
 const DATA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test_data");
 
+pub mod channels;
 pub mod lowlevel;
+#[cfg(feature = "dasp")]
+pub mod resample;
 pub mod sample;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -19,24 +22,32 @@ pub struct WavFileDesc<T> {
     pub channels: u16,
     pub sample_rate: u32,
     pub length: usize,
+    /// Requested speaker layout (`dwChannelMask`); 0 leaves it unspecified.
+    pub channel_mask: u32,
+    /// Container byte order to emit (`RIFF` vs `RIFX`).
+    pub endianness: Endianness,
     __phantom: PhantomData<T>,
 }
 
-impl<T> From<lowlevel::WavHeader> for WavFileDesc<T> {
+impl<T: NumIO> From<lowlevel::WavHeader> for WavFileDesc<T> {
     fn from(h: lowlevel::WavHeader) -> Self {
-        assert_eq!(h.bits_per_sample as usize, std::mem::size_of::<T>() * 8);
+        // The on-disk width is the sample type's packed width, not its size in
+        // memory (`I24` packs to 3 bytes but occupies 4).
+        assert_eq!(h.bits_per_sample as usize, T::DISK_BYTES as usize * 8);
         Self {
             channels: h.channels,
             sample_rate: h.sample_rate,
             length: (h.data_size / h.bytes_per_block as u32) as usize,
+            channel_mask: h.channel_mask,
+            endianness: h.endianness,
             __phantom: PhantomData,
         }
     }
 }
 
-impl<T: 'static> From<WavFileDesc<T>> for lowlevel::WavHeader {
+impl<T: 'static + NumIO> From<WavFileDesc<T>> for lowlevel::WavHeader {
     fn from(desc: WavFileDesc<T>) -> Self {
-        let bytes_per_sample = std::mem::size_of::<T>();
+        let bytes_per_sample = T::DISK_BYTES as usize;
         let bits_per_sample = (8 * bytes_per_sample) as u16;
         let bytes_per_block = (desc.channels as usize * bytes_per_sample) as u16;
         Self {
@@ -48,6 +59,9 @@ impl<T: 'static> From<WavFileDesc<T>> for lowlevel::WavHeader {
             bits_per_sample,
             audio_format: AudioFormat::from_type::<T>(),
             data_size: 0,
+            channel_mask: desc.channel_mask,
+            endianness: desc.endianness,
+            skipped_chunks: Vec::new(),
         }
     }
 }
@@ -58,6 +72,8 @@ impl<T> WavFileDesc<T> {
             channels,
             sample_rate,
             length,
+            channel_mask: 0,
+            endianness: Endianness::Little,
             __phantom: PhantomData,
         }
     }
@@ -65,6 +81,19 @@ impl<T> WavFileDesc<T> {
     pub fn empty(channels: u16, sample_rate: u32) -> Self {
         Self::new(channels, sample_rate, 0)
     }
+
+    /// Request a specific speaker layout (`dwChannelMask`) for the written
+    /// `WAVE_FORMAT_EXTENSIBLE` header.
+    pub fn with_channel_mask(mut self, channel_mask: u32) -> Self {
+        self.channel_mask = channel_mask;
+        self
+    }
+
+    /// Emit the big-endian `RIFX` container variant instead of `RIFF`.
+    pub fn big_endian(mut self) -> Self {
+        self.endianness = Endianness::Big;
+        self
+    }
 }
 
 pub trait IteratorExt: Iterator {
@@ -1,18 +1,43 @@
 use std::io::{Read, Write};
 
-use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
 
 use reader::{ReadError, SampleIteratorFormat};
 use std::any::TypeId;
+use crate::sample::{Endianness, NumIO};
 use crate::WavFileDesc;
 
 pub mod reader;
 pub mod writer;
 
+/// 16-byte sub-format GUID carried by `WAVE_FORMAT_EXTENSIBLE` (`fmt ` tag
+/// `0xFFFE`), stored on disk in RIFF (little-endian) byte order.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SubFormatGuid(pub [u8; 16]);
+
+impl SubFormatGuid {
+    /// `KSDATAFORMAT_SUBTYPE_PCM`
+    pub const PCM: Self = Self([
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xaa, 0x00, 0x38, 0x9b,
+        0x71,
+    ]);
+    /// `KSDATAFORMAT_SUBTYPE_IEEE_FLOAT`
+    pub const IEEE_FLOAT: Self = Self([
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xaa, 0x00, 0x38, 0x9b,
+        0x71,
+    ]);
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum AudioFormat {
     PCMLinear,
     PCMFloat,
+    /// `WAVE_FORMAT_EXTENSIBLE`: the true format lives in `sub_format`.
+    Extensible {
+        valid_bits: u16,
+        channel_mask: u32,
+        sub_format: SubFormatGuid,
+    },
     Unknown(u16),
 }
 
@@ -25,12 +50,31 @@ impl AudioFormat {
         }
     }
 
-    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), writer::WriteError> {
-        writer.write_u16::<LittleEndian>(match self {
+    /// Resolve `Extensible` onto the concrete format its sub-format GUID names;
+    /// every other variant is returned unchanged.
+    pub fn effective(&self) -> Self {
+        match self {
+            &Self::Extensible { sub_format, .. } => match sub_format {
+                SubFormatGuid::PCM => Self::PCMLinear,
+                SubFormatGuid::IEEE_FLOAT => Self::PCMFloat,
+                _ => *self,
+            },
+            other => *other,
+        }
+    }
+
+    /// The 16-bit `wFormatTag` value for this format.
+    pub fn tag(&self) -> u16 {
+        match self {
             Self::PCMLinear => 1,
             Self::PCMFloat => 3,
+            Self::Extensible { .. } => 0xFFFE,
             &Self::Unknown(x) => x,
-        })?;
+        }
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), writer::WriteError> {
+        writer.write_u16::<LittleEndian>(self.tag())?;
         Ok(())
     }
 }
@@ -46,12 +90,17 @@ impl AudioFormat {
         } else if type_eq!(T, u8) || type_eq!(T, i16) || type_eq!(T, i32) || type_eq!(T, i64) {
             Self::PCMLinear
         } else {
+            // `dasp_sample::I24`/`I48` are packed linear PCM on disk.
+            #[cfg(feature = "dasp")]
+            if type_eq!(T, ::dasp_sample::I24) || type_eq!(T, ::dasp_sample::I48) {
+                return Self::PCMLinear;
+            }
             Self::Unknown(0)
         }
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct WavHeader {
     /// File size in bytes, minus 8 byutes
     pub file_size: u32,
@@ -69,6 +118,14 @@ pub struct WavHeader {
     pub bits_per_sample: u16,
     /// Data block size (bytes)
     pub data_size: u32,
+    /// Speaker layout from a `WAVE_FORMAT_EXTENSIBLE` `dwChannelMask` (0 when
+    /// unspecified).
+    pub channel_mask: u32,
+    /// Container byte order: `Little` for `RIFF`, `Big` for `RIFX`.
+    pub endianness: Endianness,
+    /// Chunks encountered before `data` that the parser did not interpret,
+    /// kept as `(id, size)` pairs so callers can inspect metadata.
+    pub skipped_chunks: Vec<([u8; 4], u32)>,
 }
 
 macro_rules! expect_magic {
@@ -81,25 +138,143 @@ macro_rules! expect_magic {
     };
 }
 
+/// Render a chunk id as a lossy string for error messages.
+fn chunk_tag(id: &[u8; 4]) -> String {
+    String::from_utf8_lossy(id).to_string()
+}
+
+/// Read a `u16` in the container's byte order.
+fn read_u16_e<R: Read>(reader: &mut R, endian: Endianness) -> std::io::Result<u16> {
+    match endian {
+        Endianness::Little => reader.read_u16::<LittleEndian>(),
+        Endianness::Big => reader.read_u16::<BigEndian>(),
+    }
+}
+
+/// Read a `u32` in the container's byte order.
+fn read_u32_e<R: Read>(reader: &mut R, endian: Endianness) -> std::io::Result<u32> {
+    match endian {
+        Endianness::Little => reader.read_u32::<LittleEndian>(),
+        Endianness::Big => reader.read_u32::<BigEndian>(),
+    }
+}
+
+/// Write a `u16` in the container's byte order.
+fn write_u16_e<W: Write>(writer: &mut W, v: u16, endian: Endianness) -> std::io::Result<()> {
+    match endian {
+        Endianness::Little => writer.write_u16::<LittleEndian>(v),
+        Endianness::Big => writer.write_u16::<BigEndian>(v),
+    }
+}
+
+/// Write a `u32` in the container's byte order.
+fn write_u32_e<W: Write>(writer: &mut W, v: u32, endian: Endianness) -> std::io::Result<()> {
+    match endian {
+        Endianness::Little => writer.write_u32::<LittleEndian>(v),
+        Endianness::Big => writer.write_u32::<BigEndian>(v),
+    }
+}
+
+/// Consume `to_skip` remaining bytes of a chunk from `reader`, plus the RIFF
+/// pad byte when the chunk's *declared* `size` is odd, mapping a premature end
+/// of stream onto [`ReadError::TruncatedChunk`]. The pad is a property of the
+/// whole chunk, so its parity is taken from `size`, not from the residual
+/// `to_skip`.
+fn skip_chunk<R: Read>(
+    reader: &mut R,
+    id: &[u8; 4],
+    to_skip: u32,
+    size: u32,
+) -> Result<(), ReadError> {
+    let padded = to_skip as u64 + (size & 1) as u64;
+    let mut buf = [0u8; 4096];
+    let mut left = padded;
+    while left > 0 {
+        let take = left.min(buf.len() as u64) as usize;
+        reader.read_exact(&mut buf[..take]).map_err(|e| match e.kind() {
+            std::io::ErrorKind::UnexpectedEof => ReadError::TruncatedChunk(chunk_tag(id), size),
+            _ => ReadError::IOError(e),
+        })?;
+        left -= take as u64;
+    }
+    Ok(())
+}
+
 impl WavHeader {
     pub fn from_reader<R: Read>(mut reader: R) -> Result<(Self, R), ReadError> {
-        expect_magic!(read reader, b"RIFF", ReadError::ExpectedRIFF);
+        // `RIFF` is little-endian, `RIFX` the big-endian variant; the byte
+        // order of every numeric field below follows this tag.
+        let mut tag = [0u8; 4];
+        reader.read_exact(&mut tag)?;
+        let endianness = match &tag {
+            b"RIFF" => Endianness::Little,
+            b"RIFX" => Endianness::Big,
+            _ => return Err(ReadError::ExpectedRIFF(chunk_tag(&tag))),
+        };
 
-        let file_size = reader.read_u32::<LittleEndian>()?;
+        let file_size = read_u32_e(&mut reader, endianness)?;
 
         expect_magic!(read reader, b"WAVE", ReadError::ExpectedWAVE);
-        expect_magic!(read reader, b"fmt ", ReadError::ExpectedFmt);
 
-        let _ = reader.read_u32::<LittleEndian>()?; // Discard the subckunk size
-        let audio_format = AudioFormat::from_u16(reader.read_u16::<LittleEndian>()?);
-        let channels = reader.read_u16::<LittleEndian>()?;
-        let sample_rate = reader.read_u32::<LittleEndian>()?;
-        let bytes_per_sec = reader.read_u32::<LittleEndian>()?;
-        let bytes_per_block = reader.read_u16::<LittleEndian>()?;
-        let bits_per_sample = reader.read_u16::<LittleEndian>()?;
+        let mut audio_format = None;
+        let mut channels = 0;
+        let mut sample_rate = 0;
+        let mut bytes_per_sec = 0;
+        let mut bytes_per_block = 0;
+        let mut bits_per_sample = 0;
+        let mut channel_mask = 0;
+        let mut skipped_chunks = Vec::new();
+
+        // Walk the RIFF chunks until the `data` chunk is reached, dispatching on
+        // the chunk id and skipping anything we do not understand.
+        let data_size = loop {
+            let mut id = [0u8; 4];
+            reader.read_exact(&mut id)?;
+            let size = read_u32_e(&mut reader, endianness)?;
+            match &id {
+                b"fmt " => {
+                    // We understand the first 16 bytes; beyond them may sit a
+                    // `cbSize` extension block, interpreted only for
+                    // `WAVE_FORMAT_EXTENSIBLE` and otherwise skipped.
+                    let mut extra = size
+                        .checked_sub(16)
+                        .ok_or_else(|| ReadError::OverflowingChunk(chunk_tag(&id), size))?;
+                    let tag = read_u16_e(&mut reader, endianness)?;
+                    channels = read_u16_e(&mut reader, endianness)?;
+                    sample_rate = read_u32_e(&mut reader, endianness)?;
+                    bytes_per_sec = read_u32_e(&mut reader, endianness)?;
+                    bytes_per_block = read_u16_e(&mut reader, endianness)?;
+                    bits_per_sample = read_u16_e(&mut reader, endianness)?;
+
+                    let mut fmt = AudioFormat::from_u16(tag);
+                    if extra >= 2 {
+                        let cb_size = read_u16_e(&mut reader, endianness)?;
+                        extra -= 2;
+                        if tag == 0xFFFE && cb_size >= 22 && extra >= 22 {
+                            let valid_bits = read_u16_e(&mut reader, endianness)?;
+                            channel_mask = read_u32_e(&mut reader, endianness)?;
+                            let mut guid = [0u8; 16];
+                            reader.read_exact(&mut guid)?;
+                            extra -= 22;
+                            fmt = AudioFormat::Extensible {
+                                valid_bits,
+                                channel_mask,
+                                sub_format: SubFormatGuid(guid),
+                            };
+                        }
+                    }
+                    audio_format = Some(fmt);
+                    skip_chunk(&mut reader, &id, extra, size)?;
+                }
+                b"data" => break size,
+                _ => {
+                    skip_chunk(&mut reader, &id, size, size)?;
+                    skipped_chunks.push((id, size));
+                }
+            }
+        };
 
-        expect_magic!(read reader, b"data", ReadError::ExpectedData);
-        let data_size = reader.read_u32::<LittleEndian>()?;
+        let audio_format = audio_format.ok_or(ReadError::MissingFmt)?;
 
         Ok((Self {
             file_size,
@@ -110,28 +285,63 @@ impl WavHeader {
             bytes_per_block,
             bits_per_sample,
             data_size,
+            channel_mask,
+            endianness,
+            skipped_chunks,
         }, reader))
     }
 
+    /// Whether [`WavHeader::write`] emits the 40-byte `WAVE_FORMAT_EXTENSIBLE`
+    /// `fmt ` layout: modern tools require it for >2-channel or non-8/16-bit
+    /// audio.
+    pub fn extensible(&self) -> bool {
+        self.channels > 2 || !matches!(self.bits_per_sample, 8 | 16)
+    }
+
+    /// Byte offset of the `data` chunk's size field in a stream written by
+    /// [`WavHeader::write`], accounting for the extensible `fmt ` extension.
+    pub(crate) fn data_size_offset(&self) -> u64 {
+        let fmt_body = if self.extensible() { 40 } else { 16 };
+        // RIFF + file size + WAVE + "fmt " + fmt size + fmt body + "data"
+        4 + 4 + 4 + 4 + 4 + fmt_body + 4
+    }
+
     /// /!\ Does not write the data size (unknown for the header)
     pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), writer::WriteError> {
-        writer.write(b"RIFF")?;
-        writer.write_u32::<LittleEndian>(self.file_size)?;
+        let extensible = self.extensible();
+        let endian = self.endianness;
+
+        writer.write(match endian {
+            Endianness::Little => b"RIFF",
+            Endianness::Big => b"RIFX",
+        })?;
+        write_u32_e(writer, self.file_size, endian)?;
         writer.write(b"WAVEfmt ")?;
-        writer.write_u32::<LittleEndian>(16)?;
-        self.audio_format.write(writer)?;
-        writer.write_u16::<LittleEndian>(self.channels)?;
-        writer.write_u32::<LittleEndian>(self.sample_rate)?;
-        writer.write_u32::<LittleEndian>(self.bytes_per_sec)?;
-        writer.write_u16::<LittleEndian>(self.bytes_per_block)?;
-        writer.write_u16::<LittleEndian>(self.bits_per_sample)?;
+        write_u32_e(writer, if extensible { 40 } else { 16 }, endian)?;
+        let tag = if extensible { 0xFFFE } else { self.audio_format.tag() };
+        write_u16_e(writer, tag, endian)?;
+        write_u16_e(writer, self.channels, endian)?;
+        write_u32_e(writer, self.sample_rate, endian)?;
+        write_u32_e(writer, self.bytes_per_sec, endian)?;
+        write_u16_e(writer, self.bytes_per_block, endian)?;
+        write_u16_e(writer, self.bits_per_sample, endian)?;
+        if extensible {
+            let sub_format = match self.audio_format.effective() {
+                AudioFormat::PCMFloat => SubFormatGuid::IEEE_FLOAT,
+                _ => SubFormatGuid::PCM,
+            };
+            write_u16_e(writer, 22, endian)?; // cbSize
+            write_u16_e(writer, self.bits_per_sample, endian)?; // wValidBitsPerSample
+            write_u32_e(writer, self.channel_mask, endian)?;
+            writer.write(&sub_format.0)?;
+        }
 
         writer.write(b"data")?;
         Ok(())
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct WavFile<R> {
     header: WavHeader,
     pub(crate) data: R,
@@ -156,17 +366,28 @@ impl<R: Read> WavFile<R> {
         use AudioFormat::{PCMLinear, PCMFloat};
         use reader::WavSampleIterator;
         use std::marker::PhantomData;
-        let val = match (self.header.bytes_per_block / self.header.channels, self.header.audio_format) {
-            (1, PCMLinear) => U8(WavSampleIterator { file: self, __type: PhantomData }),
-            (2, PCMLinear) => I16(WavSampleIterator { file: self, __type: PhantomData }),
+        let bytes = (self.header.bytes_per_block / self.header.channels) as u8;
+        let endian = self.header.endianness;
+        // For EXTENSIBLE streams the real precision lives in `wValidBitsPerSample`.
+        #[cfg_attr(not(feature = "dasp"), allow(unused_variables))]
+        let valid_bits = match self.header.audio_format {
+            AudioFormat::Extensible { valid_bits, .. } => valid_bits,
+            _ => self.header.bits_per_sample,
+        };
+        let val = match (bytes, self.header.audio_format.effective()) {
+            (1, PCMLinear) => U8(WavSampleIterator { file: self, bytes, endian, __type: PhantomData }),
+            (2, PCMLinear) => I16(WavSampleIterator { file: self, bytes, endian, __type: PhantomData }),
             #[cfg(feature = "dasp")]
-            (3, PCMLinear) => I24(WavSampleIterator { file: self, __type: PhantomData }),
-            (4, PCMLinear) => I32(WavSampleIterator { file: self, __type: PhantomData }),
-            (4, PCMFloat) => F32(WavSampleIterator { file: self, __type: PhantomData }),
+            (3, PCMLinear) => I24(WavSampleIterator { file: self, bytes, endian, __type: PhantomData }),
+            // 24-bit left-justified in a 4-byte block: decode as packed `I24`.
             #[cfg(feature = "dasp")]
-            (6, PCMLinear) => I48(WavSampleIterator { file: self, __type: PhantomData }),
-            (8, PCMLinear) => I64(WavSampleIterator { file: self, __type: PhantomData }),
-            (8, PCMFloat) => F64(WavSampleIterator { file: self, __type: PhantomData }),
+            (4, PCMLinear) if valid_bits == 24 => I24(WavSampleIterator { file: self, bytes, endian, __type: PhantomData }),
+            (4, PCMLinear) => I32(WavSampleIterator { file: self, bytes, endian, __type: PhantomData }),
+            (4, PCMFloat) => F32(WavSampleIterator { file: self, bytes, endian, __type: PhantomData }),
+            #[cfg(feature = "dasp")]
+            (6, PCMLinear) => I48(WavSampleIterator { file: self, bytes, endian, __type: PhantomData }),
+            (8, PCMLinear) => I64(WavSampleIterator { file: self, bytes, endian, __type: PhantomData }),
+            (8, PCMFloat) => F64(WavSampleIterator { file: self, bytes, endian, __type: PhantomData }),
             _ => return Err(())
         };
         Ok(val)
@@ -174,7 +395,7 @@ impl<R: Read> WavFile<R> {
 }
 
 impl<W: Write> WavFile<W> {
-    pub fn write<T: 'static>(desc: WavFileDesc<T>, writer: W) -> Self {
+    pub fn write<T: 'static + NumIO>(desc: WavFileDesc<T>, writer: W) -> Self {
         Self {
             header: desc.into(),
             data: writer,
@@ -275,4 +496,201 @@ mod tests {
         assert_eq!(iter.next(), Some(0.0));
         assert!(iter.next().is_none());
     }
+
+    use std::io::Cursor;
+
+    use crate::lowlevel::reader::ReadError;
+
+    /// Append a `(id, size, payload)` chunk with its RIFF pad byte.
+    fn push_chunk(v: &mut Vec<u8>, id: &[u8; 4], payload: &[u8]) {
+        v.extend_from_slice(id);
+        v.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        v.extend_from_slice(payload);
+        if payload.len() % 2 == 1 {
+            v.push(0);
+        }
+    }
+
+    /// A mono 16-bit PCM stream carrying `fact` and an odd-length `LIST` chunk
+    /// between `fmt ` and `data`.
+    fn wav_with_metadata() -> Vec<u8> {
+        let mut v = Vec::new();
+        v.extend_from_slice(b"RIFF");
+        v.extend_from_slice(&0u32.to_le_bytes());
+        v.extend_from_slice(b"WAVE");
+
+        let mut fmt = Vec::new();
+        fmt.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        fmt.extend_from_slice(&1u16.to_le_bytes()); // channels
+        fmt.extend_from_slice(&44100u32.to_le_bytes());
+        fmt.extend_from_slice(&88200u32.to_le_bytes());
+        fmt.extend_from_slice(&2u16.to_le_bytes()); // block align
+        fmt.extend_from_slice(&16u16.to_le_bytes()); // bits
+        push_chunk(&mut v, b"fmt ", &fmt);
+        push_chunk(&mut v, b"fact", &0u32.to_le_bytes());
+        push_chunk(&mut v, b"LIST", b"INFOx"); // 5 bytes -> padded
+        push_chunk(&mut v, b"data", &1234i16.to_le_bytes());
+        v
+    }
+
+    #[test]
+    fn test_skips_metadata_chunks() {
+        let bytes = wav_with_metadata();
+        let (header, _) = WavHeader::from_reader(Cursor::new(bytes)).unwrap();
+        assert_eq!(header.channels, 1);
+        assert_eq!(header.sample_rate, 44100);
+        assert_eq!(header.bits_per_sample, 16);
+        assert_eq!(header.data_size, 2);
+        assert_eq!(header.skipped_chunks, vec![(*b"fact", 4), (*b"LIST", 5)]);
+    }
+
+    #[test]
+    fn test_odd_padded_chunk_keeps_stream_aligned() {
+        // The odd `LIST` chunk must not desync the reader: the sample after the
+        // header is read back intact.
+        let file = super::WavFile::from_reader(Cursor::new(wav_with_metadata())).unwrap();
+        use crate::lowlevel::reader::SampleIteratorFormat::*;
+        match file.samples().unwrap() {
+            I16(mut it) => {
+                assert_eq!(it.next(), Some(1234));
+                assert!(it.next().is_none());
+            }
+            _ => panic!("Unexpected iterator format"),
+        }
+    }
+
+    #[test]
+    fn test_truncated_chunk_rejected() {
+        let mut v = Vec::new();
+        v.extend_from_slice(b"RIFF");
+        v.extend_from_slice(&0u32.to_le_bytes());
+        v.extend_from_slice(b"WAVE");
+        // A chunk claiming 100 bytes but carrying only 2.
+        v.extend_from_slice(b"junk");
+        v.extend_from_slice(&100u32.to_le_bytes());
+        v.extend_from_slice(&[0u8, 0u8]);
+        let err = WavHeader::from_reader(Cursor::new(v)).unwrap_err();
+        assert!(matches!(err, ReadError::TruncatedChunk(..)));
+    }
+
+    #[cfg(feature = "dasp")]
+    #[test]
+    fn test_packed_i24_round_trip() {
+        use crate::lowlevel::writer::WavWriter;
+        use crate::lowlevel::reader::SampleIteratorFormat::*;
+        use crate::WavFileDesc;
+        use dasp_sample::I24;
+
+        let desc = WavFileDesc::<I24>::empty(1, 48000);
+        let mut writer = WavWriter::from_file(super::WavFile::write(desc, Cursor::new(vec![])));
+        let values = [0, 1000, -1000, (1 << 23) - 1, -(1 << 23)];
+        for &v in &values {
+            writer.write_sample(I24::new_unchecked(v)).unwrap();
+        }
+        let mut data = writer.into_inner().unwrap();
+
+        // A genuinely packed 24-bit stream: 3 bytes per sample, linear PCM
+        // (carried through the EXTENSIBLE sub-format for non-8/16-bit audio).
+        let (header, _) = WavHeader::from_reader(data.clone()).unwrap();
+        assert_eq!(header.bits_per_sample, 24);
+        assert_eq!(header.bytes_per_block, 3);
+        assert_eq!(header.audio_format.effective(), AudioFormat::PCMLinear);
+
+        data.set_position(0);
+        match super::WavFile::from_reader(data).unwrap().samples().unwrap() {
+            I24(it) => {
+                let got: Vec<i32> = it.map(|s| s.inner()).collect();
+                assert_eq!(got, values);
+            }
+            _ => panic!("Unexpected iterator format"),
+        }
+    }
+
+    #[test]
+    fn test_rifx_round_trip() {
+        use crate::lowlevel::writer::WavWriter;
+        use crate::lowlevel::reader::SampleIteratorFormat::*;
+        use crate::sample::Endianness;
+        use crate::WavFileDesc;
+
+        let desc = WavFileDesc::<i16>::empty(1, 44100).big_endian();
+        let mut writer = WavWriter::from_file(super::WavFile::write(desc, Cursor::new(vec![])));
+        let values = [0i16, 1000, -1000, i16::MAX, i16::MIN];
+        for &v in &values {
+            writer.write_sample(v).unwrap();
+        }
+        let mut data = writer.into_inner().unwrap();
+        assert_eq!(&data.get_ref()[..4], b"RIFX");
+
+        let (header, _) = WavHeader::from_reader(data.clone()).unwrap();
+        assert_eq!(header.endianness, Endianness::Big);
+        assert_eq!(header.sample_rate, 44100);
+
+        data.set_position(0);
+        match super::WavFile::from_reader(data).unwrap().samples().unwrap() {
+            I16(it) => assert_eq!(it.collect::<Vec<_>>(), values.to_vec()),
+            _ => panic!("Unexpected iterator format"),
+        }
+    }
+
+    #[test]
+    fn test_frame_round_trip_stereo() {
+        use crate::lowlevel::writer::WavWriter;
+        use crate::lowlevel::reader::SampleIteratorFormat::*;
+        use crate::WavFileDesc;
+
+        let desc = WavFileDesc::<i16>::empty(2, 48000);
+        let mut writer = WavWriter::from_file(super::WavFile::write(desc, Cursor::new(vec![])));
+        let frames = [[1i16, -1], [2, -2], [3, -3]];
+        writer.write_frames(frames.iter().copied()).unwrap();
+        let mut data = writer.into_inner().unwrap();
+
+        data.set_position(0);
+        let file = super::WavFile::from_reader(data).unwrap();
+        match file.samples().unwrap() {
+            I16(it) => {
+                let got: Vec<Vec<i16>> = it.frames().collect();
+                assert_eq!(got, vec![vec![1, -1], vec![2, -2], vec![3, -3]]);
+            }
+            _ => panic!("Unexpected iterator format"),
+        }
+    }
+
+    #[test]
+    fn test_planar_deinterleave_stereo() {
+        use crate::lowlevel::writer::WavWriter;
+        use crate::lowlevel::reader::SampleIteratorFormat::*;
+        use crate::WavFileDesc;
+
+        let desc = WavFileDesc::<i16>::empty(2, 48000);
+        let mut writer = WavWriter::from_file(super::WavFile::write(desc, Cursor::new(vec![])));
+        writer
+            .write_frames([[10i16, 20], [11, 21], [12, 22]].iter().copied())
+            .unwrap();
+        let mut data = writer.into_inner().unwrap();
+
+        data.set_position(0);
+        let file = super::WavFile::from_reader(data).unwrap();
+        match file.samples().unwrap() {
+            I16(it) => {
+                let planar = it.frames().planar();
+                assert_eq!(planar, vec![vec![10, 11, 12], vec![20, 21, 22]]);
+            }
+            _ => panic!("Unexpected iterator format"),
+        }
+    }
+
+    #[test]
+    fn test_overflowing_fmt_size_rejected() {
+        let mut v = Vec::new();
+        v.extend_from_slice(b"RIFF");
+        v.extend_from_slice(&0u32.to_le_bytes());
+        v.extend_from_slice(b"WAVE");
+        // `fmt ` declaring fewer than the mandatory 16 bytes.
+        v.extend_from_slice(b"fmt ");
+        v.extend_from_slice(&8u32.to_le_bytes());
+        v.extend_from_slice(&[0u8; 8]);
+        let err = WavHeader::from_reader(Cursor::new(v)).unwrap_err();
+        assert!(matches!(err, ReadError::OverflowingChunk(..)));
+    }
 }
\ No newline at end of file
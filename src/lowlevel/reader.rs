@@ -1,6 +1,6 @@
 use crate::lowlevel::WavFile;
 use std::marker::PhantomData;
-use crate::sample::NumIO;
+use crate::sample::{Endianness, NumIO};
 use std::io::Read;
 
 #[derive(Error, Debug)]
@@ -13,12 +13,22 @@ pub enum ReadError {
     ExpectedFmt(String),
     #[error("Unexpected {0}, expecting magic number 'data'")]
     ExpectedData(String),
+    #[error("Missing 'fmt ' chunk before 'data'")]
+    MissingFmt,
+    #[error("Chunk '{0}' declares size {1}, which is truncated in the stream")]
+    TruncatedChunk(String, u32),
+    #[error("Chunk '{0}' declares an invalid/overflowing size {1}")]
+    OverflowingChunk(String, u32),
     #[error("I/O Error: {0}")]
     IOError(#[from] std::io::Error),
 }
 
 pub struct WavSampleIterator<T, R> {
     pub(crate) file: WavFile<R>,
+    /// On-disk width of a single sample, derived from `bytes_per_block`.
+    pub(crate) bytes: u8,
+    /// Container byte order the samples are stored in.
+    pub(crate) endian: Endianness,
     pub(crate) __type: PhantomData<T>,
 }
 
@@ -26,7 +36,7 @@ impl<T: NumIO, R: Read> Iterator for WavSampleIterator<T, R> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        T::read(&mut self.file.data).ok()
+        T::read_with(&mut self.file.data, self.bytes, self.endian).ok()
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -41,6 +51,64 @@ impl<T: NumIO, R: Read> ExactSizeIterator for WavSampleIterator<T, R> {
     }
 }
 
+impl<T: NumIO, R: Read> WavSampleIterator<T, R> {
+    /// Group the flat sample stream into interleaved frames of
+    /// `header.channels` samples each, the read-side counterpart to
+    /// [`crate::lowlevel::writer::WavWriter::write_frames`].
+    pub fn frames(self) -> FrameIterator<T, R> {
+        let channels = self.file.header.channels as usize;
+        FrameIterator {
+            iter: self,
+            channels,
+        }
+    }
+}
+
+/// Iterator yielding one interleaved frame (`channels` samples) at a time. A
+/// trailing partial frame at end-of-stream is dropped.
+pub struct FrameIterator<T, R> {
+    iter: WavSampleIterator<T, R>,
+    channels: usize,
+}
+
+impl<T: NumIO, R: Read> FrameIterator<T, R> {
+    /// Number of channels per frame.
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Collect the remaining frames into a planar (per-channel) view: the
+    /// outer `Vec` has one entry per channel, each holding that channel's
+    /// samples in order.
+    pub fn planar(self) -> Vec<Vec<T>> {
+        let channels = self.channels;
+        let mut out: Vec<Vec<T>> = (0..channels).map(|_| Vec::new()).collect();
+        for frame in self {
+            for (c, sample) in frame.into_iter().enumerate() {
+                out[c].push(sample);
+            }
+        }
+        out
+    }
+}
+
+impl<T: NumIO, R: Read> Iterator for FrameIterator<T, R> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut frame = Vec::with_capacity(self.channels);
+        for _ in 0..self.channels {
+            frame.push(self.iter.next()?);
+        }
+        Some(frame)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let frames = self.iter.len() / self.channels.max(1);
+        (frames, Some(frames))
+    }
+}
+
 pub enum SampleIteratorFormat<R> {
     U8(WavSampleIterator<u8, R>),
     I16(WavSampleIterator<i16, R>),
@@ -56,25 +124,29 @@ pub enum SampleIteratorFormat<R> {
 
 #[cfg(feature = "dasp")]
 impl<R: Read> SampleIteratorFormat<R> {
-    pub fn sampled<T>(self) -> impl Iterator<Item=T>
-        where T: dasp_sample::Sample +
+    pub fn sampled<T>(self) -> Box<dyn Iterator<Item=T> + 'static>
+        where T: 'static +
+        dasp_sample::Sample +
         dasp_sample::FromSample<u8> +
         dasp_sample::FromSample<i16> +
         dasp_sample::FromSample<dasp_sample::I24> +
         dasp_sample::FromSample<i32> +
         dasp_sample::FromSample<i64> +
         dasp_sample::FromSample<f32> +
-        dasp_sample::FromSample<f64> {
+        dasp_sample::FromSample<f64>,
+        R: 'static {
         use SampleIteratorFormat::*;
+        // Each arm yields a distinct `Map<…>` type, so erase them behind a
+        // trait object to give the match a single return type.
         match self {
-            U8(it) => it.map(dasp_sample::FromSample::from_sample_),
-            I16(it) => it.map(dasp_sample::FromSample::from_sample_),
-            I24(it) => it.map(dasp_sample::FromSample::from_sample_),
-            I32(it) => it.map(dasp_sample::FromSample::from_sample_),
-            I48(it) => it.map(dasp_sample::FromSample::from_sample_),
-            I64(it) => it.map(dasp_sample::FromSample::from_sample_),
-            F32(it) => it.map(dasp_sample::FromSample::from_sample_),
-            F64(it) => it.map(dasp_sample::FromSample::from_sample_),
+            U8(it) => Box::new(it.map(dasp_sample::FromSample::from_sample_)),
+            I16(it) => Box::new(it.map(dasp_sample::FromSample::from_sample_)),
+            I24(it) => Box::new(it.map(dasp_sample::FromSample::from_sample_)),
+            I32(it) => Box::new(it.map(dasp_sample::FromSample::from_sample_)),
+            I48(it) => Box::new(it.map(dasp_sample::FromSample::from_sample_)),
+            I64(it) => Box::new(it.map(dasp_sample::FromSample::from_sample_)),
+            F32(it) => Box::new(it.map(dasp_sample::FromSample::from_sample_)),
+            F64(it) => Box::new(it.map(dasp_sample::FromSample::from_sample_)),
         }
     }
 }
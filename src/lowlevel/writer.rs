@@ -4,8 +4,16 @@ use std::iter::FromIterator;
 use std::marker::PhantomData;
 
 use crate::lowlevel::WavFile;
-use crate::sample::NumIO;
-use byteorder::{WriteBytesExt, LittleEndian};
+use crate::sample::{Endianness, NumIO};
+use byteorder::{WriteBytesExt, BigEndian, LittleEndian};
+
+/// Write a `u32` in the writer's container byte order.
+fn write_u32<W: Write>(writer: &mut W, v: u32, endian: Endianness) -> std::io::Result<()> {
+    match endian {
+        Endianness::Little => writer.write_u32::<LittleEndian>(v),
+        Endianness::Big => writer.write_u32::<BigEndian>(v),
+    }
+}
 
 #[derive(Error, Debug)]
 pub enum WriteError {
@@ -17,6 +25,10 @@ pub enum WriteError {
 pub struct WavWriter<T, W: Seek + Write> {
     file: WavFile<W>,
     data_size: u32,
+    /// On-disk width of a single sample, derived from `bytes_per_block`.
+    bytes: u8,
+    /// Container byte order to emit samples in.
+    endian: Endianness,
     __phantom: PhantomData<T>,
 }
 
@@ -28,18 +40,22 @@ impl<T, W: Seek + Write> Drop for WavWriter<T, W> {
 
 impl<T: NumIO, W: Seek + Write> WavWriter<T, W> {
     pub(crate) fn from_file(mut file: WavFile<W>) -> Self {
-        assert_eq!(file.header.bits_per_sample as usize, 8 * std::mem::size_of::<T>());
+        let bytes = (file.header.bytes_per_block / file.header.channels) as u8;
+        assert!(bytes as usize <= std::mem::size_of::<T>());
+        let endian = file.header.endianness;
         file.header.write(&mut file.data);
         Self {
             file,
             data_size: 0,
+            bytes,
+            endian,
             __phantom: PhantomData,
         }
     }
 
     pub fn write_sample(&mut self, sample: T) -> Result<(), WriteError> {
-        sample.write(&mut self.file.data)?;
-        self.data_size += 1;
+        sample.write_with(&mut self.file.data, self.bytes, self.endian)?;
+        self.data_size += self.bytes as u32;
         Ok(())
     }
 
@@ -49,17 +65,57 @@ impl<T: NumIO, W: Seek + Write> WavWriter<T, W> {
         }
         Ok(())
     }
+
+    /// Write a stream of fixed-size channel frames interleaved on disk. The
+    /// array length must match `header.channels`.
+    pub fn write_frames<const N: usize, I: Iterator<Item = [T; N]>>(
+        &mut self,
+        frames: I,
+    ) -> Result<(), WriteError>
+    where
+        T: Copy,
+    {
+        assert_eq!(N, self.file.header.channels as usize);
+        for frame in frames {
+            for i in 0..N {
+                self.write_sample(frame[i])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Like [`WavWriter::write_frames`] but for a runtime channel count: each
+    /// slice must hold exactly `header.channels` samples.
+    pub fn write_frames_slice<'a, I: Iterator<Item = &'a [T]>>(
+        &mut self,
+        frames: I,
+    ) -> Result<(), WriteError>
+    where
+        T: 'a + Copy,
+    {
+        let channels = self.file.header.channels as usize;
+        for frame in frames {
+            assert_eq!(frame.len(), channels);
+            for &sample in frame {
+                self.write_sample(sample)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<T, W: Seek + Write> WavWriter<T, W> {
     fn patch_file(&mut self) -> Result<(), WriteError> {
-        let file_size = self.data_size + 40; // Data size + header size - 4 bytes (position of the file size attribute)
+        let data_offset = self.file.header.data_size_offset();
+        // RIFF size = everything after the 8-byte "RIFF<size>" prefix.
+        let file_size = self.data_size + data_offset as u32 - 4;
+        let endian = self.endian;
         let data = &mut self.file.data;
         data.seek(SeekFrom::Start(4));
-        data.write_u32::<LittleEndian>(file_size);
+        write_u32(data, file_size, endian);
 
-        data.seek(SeekFrom::Start(40));
-        data.write_u32::<LittleEndian>(self.data_size);
+        data.seek(SeekFrom::Start(data_offset));
+        write_u32(data, self.data_size, endian);
         data.seek(SeekFrom::End(0));
         Ok(())
     }
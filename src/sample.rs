@@ -1,28 +1,223 @@
 use std::io::{Read, Write};
 
+/// Byte order of a sample stream / RIFF container (`RIFF` is little-endian,
+/// `RIFX` big-endian).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
 pub trait NumIO: Sized {
-    fn read<R: Read>(reader: &mut R) -> std::io::Result<Self>;
-    fn write<W: Write>(&self, writer: &mut W) -> std::io::Result<()>;
+    /// On-disk width of a single sample in bytes. This is independent of the
+    /// in-memory size: 24-bit PCM is three bytes even though it decodes into a
+    /// wider integer.
+    const DISK_BYTES: u8;
+
+    /// Read one PCM sample packed into exactly `bytes` bytes on disk in the
+    /// given byte order. For signed samples the top bit of the most-significant
+    /// on-disk byte is sign-extended into the remaining bytes.
+    fn read_with<R: Read>(reader: &mut R, bytes: u8, endian: Endianness)
+        -> std::io::Result<Self>;
+    /// Write one PCM sample using only the significant `bytes` bytes of its
+    /// representation in the given byte order, the inverse of
+    /// [`NumIO::read_with`].
+    fn write_with<W: Write>(
+        &self,
+        writer: &mut W,
+        bytes: u8,
+        endian: Endianness,
+    ) -> std::io::Result<()>;
+    /// Lift the sample into `f64` for format-agnostic arithmetic (mixing,
+    /// resampling).
+    fn to_f64(&self) -> f64;
+    /// Build a sample from an `f64`. Integer sample types saturate at their
+    /// bounds rather than wrapping.
+    fn from_f64(value: f64) -> Self;
+
+    fn read<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        Self::read_with(reader, Self::DISK_BYTES, Endianness::Little)
+    }
+    fn write<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.write_with(writer, Self::DISK_BYTES, Endianness::Little)
+    }
+    fn read_packed<R: Read>(reader: &mut R, bytes: u8) -> std::io::Result<Self> {
+        Self::read_with(reader, bytes, Endianness::Little)
+    }
+    fn write_packed<W: Write>(&self, writer: &mut W, bytes: u8) -> std::io::Result<()> {
+        self.write_with(writer, bytes, Endianness::Little)
+    }
 }
 
 macro_rules! impl_numio {
-    ($firsttype:ty, $($types:ty),*) => { impl_numio!($firsttype); $(impl_numio!($types);)* };
-    ($type:ty) => {
+    (@int $($type:ty => $signed:expr),* $(,)?) => { $(
+        impl NumIO for $type {
+            const DISK_BYTES: u8 = ::std::mem::size_of::<$type>() as u8;
+
+            fn read_with<R: Read>(reader: &mut R, bytes: u8, endian: Endianness) -> ::std::io::Result<Self> {
+                let width = ::std::mem::size_of::<$type>();
+                let n = (bytes as usize).min(width);
+                let mut data = [0u8; ::std::mem::size_of::<$type>()];
+                match endian {
+                    Endianness::Little => {
+                        // Bytes land in the low end; sign-fill the high end.
+                        reader.read_exact(&mut data[..n])?;
+                        if $signed {
+                            let fill = if n > 0 && data[n - 1] & 0x80 != 0 { 0xff } else { 0x00 };
+                            for b in &mut data[n..] {
+                                *b = fill;
+                            }
+                        }
+                        Ok(<$type>::from_le_bytes(data))
+                    }
+                    Endianness::Big => {
+                        // Bytes land in the high end; sign-fill the low end.
+                        reader.read_exact(&mut data[width - n..])?;
+                        if $signed {
+                            let fill = if n > 0 && data[width - n] & 0x80 != 0 { 0xff } else { 0x00 };
+                            for b in &mut data[..width - n] {
+                                *b = fill;
+                            }
+                        }
+                        Ok(<$type>::from_be_bytes(data))
+                    }
+                }
+            }
+            fn write_with<W: Write>(&self, writer: &mut W, bytes: u8, endian: Endianness) -> ::std::io::Result<()> {
+                let width = ::std::mem::size_of::<$type>();
+                let n = (bytes as usize).min(width);
+                match endian {
+                    Endianness::Little => writer.write_all(&self.to_le_bytes()[..n])?,
+                    Endianness::Big => writer.write_all(&self.to_be_bytes()[width - n..])?,
+                }
+                Ok(())
+            }
+            // `f64 as {int}` saturates to the integer bounds since Rust 1.45.
+            fn to_f64(&self) -> f64 { *self as f64 }
+            fn from_f64(value: f64) -> Self { value as $type }
+        }
+    )* };
+    (@float $($type:ty),* $(,)?) => { $(
         impl NumIO for $type {
-            fn read<R: Read>(reader: &mut R) -> ::std::io::Result<Self> {
+            const DISK_BYTES: u8 = ::std::mem::size_of::<$type>() as u8;
+
+            // IEEE floats are always stored at their native width; `bytes` is
+            // carried for symmetry with the integer path but cannot be narrowed.
+            fn read_with<R: Read>(reader: &mut R, _bytes: u8, endian: Endianness) -> ::std::io::Result<Self> {
                 let mut data = [0; ::std::mem::size_of::<$type>()];
                 reader.read_exact(&mut data)?;
-                Ok(<$type>::from_le_bytes(data))
+                Ok(match endian {
+                    Endianness::Little => <$type>::from_le_bytes(data),
+                    Endianness::Big => <$type>::from_be_bytes(data),
+                })
             }
-            fn write<W: Write>(&self, writer: &mut W) -> ::std::io::Result<()> {
-                let data = self.to_le_bytes();
-                writer.write_all(&data)?;
+            fn write_with<W: Write>(&self, writer: &mut W, _bytes: u8, endian: Endianness) -> ::std::io::Result<()> {
+                match endian {
+                    Endianness::Little => writer.write_all(&self.to_le_bytes())?,
+                    Endianness::Big => writer.write_all(&self.to_be_bytes())?,
+                }
                 Ok(())
             }
+            fn to_f64(&self) -> f64 { *self as f64 }
+            fn from_f64(value: f64) -> Self { value as $type }
         }
-    };
+    )* };
 }
 
-impl_numio!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
-#[cfg(feature="dasp")]
-impl_numio!(::dasp_sample::I24, ::dasp_sample::I48);
\ No newline at end of file
+impl_numio!(@int u8 => false, u16 => false, u32 => false, u64 => false,
+            i8 => true, i16 => true, i32 => true, i64 => true);
+impl_numio!(@float f32, f64);
+
+// `dasp_sample::I24`/`I48` pack into 3/6 disk bytes but decode through the
+// widest native integer that fits them (`i32`/`i64`), so their packed path
+// delegates to that integer and then re-wraps the value.
+#[cfg(feature = "dasp")]
+impl NumIO for ::dasp_sample::I24 {
+    const DISK_BYTES: u8 = 3;
+
+    fn read_with<R: Read>(reader: &mut R, bytes: u8, endian: Endianness) -> std::io::Result<Self> {
+        let raw = i32::read_with(reader, bytes, endian)?;
+        // A 4-byte block carries the 24-bit value left-justified (low 8 bits are
+        // padding); the packed 3-byte form needs no shift.
+        let value = if bytes >= 4 { raw >> 8 } else { raw };
+        Ok(::dasp_sample::I24::new_unchecked(value))
+    }
+    fn write_with<W: Write>(&self, writer: &mut W, bytes: u8, endian: Endianness) -> std::io::Result<()> {
+        if bytes >= 4 {
+            (self.inner() << 8).write_with(writer, bytes, endian)
+        } else {
+            self.inner().write_with(writer, bytes, endian)
+        }
+    }
+    fn to_f64(&self) -> f64 {
+        self.inner() as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        let clamped = (value as i64).clamp(-(1 << 23), (1 << 23) - 1) as i32;
+        ::dasp_sample::I24::new_unchecked(clamped)
+    }
+}
+
+#[cfg(feature = "dasp")]
+impl NumIO for ::dasp_sample::I48 {
+    const DISK_BYTES: u8 = 6;
+
+    fn read_with<R: Read>(reader: &mut R, bytes: u8, endian: Endianness) -> std::io::Result<Self> {
+        Ok(::dasp_sample::I48::new_unchecked(i64::read_with(reader, bytes, endian)?))
+    }
+    fn write_with<W: Write>(&self, writer: &mut W, bytes: u8, endian: Endianness) -> std::io::Result<()> {
+        self.inner().write_with(writer, bytes, endian)
+    }
+    fn to_f64(&self) -> f64 {
+        self.inner() as f64
+    }
+    fn from_f64(value: f64) -> Self {
+        let clamped = (value as i64).clamp(-(1 << 47), (1 << 47) - 1);
+        ::dasp_sample::I48::new_unchecked(clamped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn round_trip_i32(value: i32, bytes: u8, endian: Endianness) -> i32 {
+        let mut buf = Cursor::new(Vec::new());
+        value.write_with(&mut buf, bytes, endian).unwrap();
+        assert_eq!(buf.get_ref().len(), bytes as usize);
+        buf.set_position(0);
+        i32::read_with(&mut buf, bytes, endian).unwrap()
+    }
+
+    #[test]
+    fn test_packed_signed_narrowing_round_trip() {
+        for &v in &[0, 1, -1, 1000, -1000, (1 << 23) - 1, -(1 << 23)] {
+            assert_eq!(round_trip_i32(v, 3, Endianness::Little), v, "3-byte LE round trip for {}", v);
+        }
+    }
+
+    #[test]
+    fn test_packed_write_emits_only_requested_bytes() {
+        let mut buf = Cursor::new(Vec::new());
+        (-1000i32).write_with(&mut buf, 3, Endianness::Little).unwrap();
+        assert_eq!(buf.into_inner(), vec![0x18, 0xFC, 0xFF]);
+    }
+
+    #[test]
+    fn test_big_endian_sign_fill_round_trip() {
+        // Narrowed signed values must sign-extend from the most-significant
+        // on-disk byte, which sits first in big-endian order.
+        for &v in &[0, 1, -1, 1000, -1000, (1 << 23) - 1, -(1 << 23)] {
+            assert_eq!(round_trip_i32(v, 3, Endianness::Big), v, "3-byte BE round trip for {}", v);
+        }
+    }
+
+    #[test]
+    fn test_big_endian_write_emits_significant_bytes() {
+        let mut buf = Cursor::new(Vec::new());
+        (-1000i32).write_with(&mut buf, 3, Endianness::Big).unwrap();
+        // to_be_bytes(-1000) == [0xFF, 0xFF, 0xFC, 0x18]; the low three bytes.
+        assert_eq!(buf.into_inner(), vec![0xFF, 0xFC, 0x18]);
+    }
+}